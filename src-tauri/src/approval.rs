@@ -0,0 +1,96 @@
+// ============================================
+// Access Approval
+// Gate in front of external (non-frontend)
+// credential reads: surfaces a consent prompt in
+// the desktop app and only releases secrets once
+// the user explicitly approves
+// ============================================
+
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// How long to wait for the user to respond before treating the request as denied
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Identity of the process asking for a credential over the IPC socket
+#[derive(Debug, Clone, Serialize)]
+pub struct Requester {
+    pub pid: Option<u32>,
+    pub executable_path: Option<String>,
+}
+
+/// Payload emitted to the frontend so it can render the consent prompt
+#[derive(Clone, Serialize)]
+struct ApprovalRequestPayload {
+    id: String,
+    keys: Vec<String>,
+    requester: Requester,
+}
+
+struct ApprovalState {
+    pending: Mutex<HashMap<String, mpsc::Sender<bool>>>,
+    whitelist: Mutex<HashSet<String>>,
+}
+
+static STATE: OnceLock<ApprovalState> = OnceLock::new();
+
+fn state() -> &'static ApprovalState {
+    STATE.get_or_init(|| ApprovalState {
+        pending: Mutex::new(HashMap::new()),
+        whitelist: Mutex::new(HashSet::new()),
+    })
+}
+
+/// Ask the user whether `requester` may read `keys`
+///
+/// Blocks the calling (IPC server) thread until the user responds or the
+/// request times out. Executables previously approved with "remember for
+/// this session" skip the prompt until the app restarts.
+pub fn request_approval(app: &AppHandle, requester: Requester, keys: &[String]) -> Result<bool, String> {
+    if let Some(path) = &requester.executable_path {
+        if state().whitelist.lock().unwrap().contains(path) {
+            return Ok(true);
+        }
+    }
+
+    let id = format!("{:016x}", rand::random::<u64>());
+    let (tx, rx) = mpsc::channel();
+    state().pending.lock().unwrap().insert(id.clone(), tx);
+
+    app.emit(
+        "credential-approval-request",
+        ApprovalRequestPayload {
+            id: id.clone(),
+            keys: keys.to_vec(),
+            requester,
+        },
+    )
+    .map_err(|e| format!("failed to show approval prompt: {}", e))?;
+
+    let approved = rx.recv_timeout(APPROVAL_TIMEOUT).unwrap_or(false);
+    state().pending.lock().unwrap().remove(&id);
+    Ok(approved)
+}
+
+/// Resolve a pending approval request
+///
+/// Called by the frontend once the user approves or denies the prompt shown
+/// for a `credential-approval-request` event. `remember` whitelists the
+/// requesting executable for the rest of this app session.
+#[tauri::command]
+pub fn respond_to_approval(id: String, approved: bool, executable_path: Option<String>, remember: bool) {
+    if approved && remember {
+        if let Some(path) = executable_path {
+            state().whitelist.lock().unwrap().insert(path);
+        }
+    }
+
+    if let Some(tx) = state().pending.lock().unwrap().remove(&id) {
+        let _ = tx.send(approved);
+    }
+}
@@ -0,0 +1,119 @@
+// ============================================
+// CLI
+// `teams-agent-bridge get`/`exec` subcommands that
+// talk to the already-running desktop app over the
+// local IPC socket, so scripts and meeting-join
+// automation can reuse stored secrets
+// ============================================
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+use clap::Subcommand;
+use interprocess::local_socket::LocalSocketStream;
+
+use crate::ipc::{Request, Response, SOCKET_NAME};
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Fetch a single credential and print it to stdout
+    Get {
+        /// Credential key, e.g. "acs.accessKey"
+        key: String,
+    },
+    /// Inject credentials as environment variables and run a command
+    ///
+    /// Example: `teams-agent-bridge exec --key acs.accessKey --key agent.clientSecret -- python run_agent.py`
+    Exec {
+        /// Credential key to inject (repeatable, e.g. `--key acs.accessKey
+        /// --key agent.clientSecret`). Each value is exposed to the child
+        /// process under an environment variable named after the key,
+        /// uppercased with non-alphanumeric characters replaced by `_`
+        /// (e.g. "acs.accessKey" -> "ACS_ACCESSKEY").
+        #[arg(long = "key", required = true)]
+        keys: Vec<String>,
+        /// Command to run, given after `--`
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+}
+
+/// Run the `get` subcommand: print the credential to stdout
+pub fn get(key: String) -> Result<(), String> {
+    let creds = fetch_credentials(vec![key.clone()])?;
+    match creds.get(&key) {
+        Some(value) => {
+            println!("{}", value);
+            Ok(())
+        }
+        None => Err(format!("credential '{}' not found", key)),
+    }
+}
+
+/// Run the `exec` subcommand: inject credentials as env vars and spawn `command`
+pub fn exec(keys: Vec<String>, command: Vec<String>) -> Result<(), String> {
+    let creds = fetch_credentials(keys)?;
+    let (program, args) = command.split_first().ok_or("no command given")?;
+
+    let envs: HashMap<String, String> = creds
+        .into_iter()
+        .map(|(key, value)| (env_var_name(&key), value))
+        .collect();
+
+    let status = Command::new(program)
+        .args(args)
+        .envs(&envs)
+        .status()
+        .map_err(|e| format!("failed to spawn '{}': {}", program, e))?;
+
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+/// Turn a dotted credential key like "acs.accessKey" into a usable shell
+/// environment variable name like "ACS_ACCESSKEY"
+fn env_var_name(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect()
+}
+
+/// Fetch credentials from the running desktop app over the local IPC socket
+fn fetch_credentials(keys: Vec<String>) -> Result<HashMap<String, String>, String> {
+    let stream = connect_with_retry()?;
+    let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream);
+
+    let payload = serde_json::to_string(&Request::GetCredentials { keys }).map_err(|e| e.to_string())?;
+    writeln!(writer, "{}", payload).map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| e.to_string())?;
+
+    match serde_json::from_str::<Response>(&line).map_err(|e| e.to_string())? {
+        Response::Credentials(creds) => Ok(creds),
+        Response::Error(msg) => Err(msg),
+    }
+}
+
+/// Connect to the IPC socket, retrying while the desktop app finishes starting up
+fn connect_with_retry() -> Result<LocalSocketStream, String> {
+    const MAX_ATTEMPTS: u32 = 20;
+    const RETRY_DELAY: Duration = Duration::from_millis(100);
+
+    for attempt in 0..MAX_ATTEMPTS {
+        match LocalSocketStream::connect(SOCKET_NAME) {
+            Ok(stream) => return Ok(stream),
+            Err(e) if attempt + 1 == MAX_ATTEMPTS => {
+                return Err(format!(
+                    "could not reach the Teams Agent Bridge app ({}); is it running?",
+                    e
+                ))
+            }
+            Err(_) => thread::sleep(RETRY_DELAY),
+        }
+    }
+    unreachable!()
+}
@@ -3,11 +3,11 @@
 // Exposed commands callable from the frontend
 // ============================================
 
-use keyring::Entry;
+use serde::Serialize;
 use tauri::command;
 
-/// Service name used for all credentials
-const SERVICE_NAME: &str = "teams-agent-bridge";
+use crate::credential::CredentialEnvelope;
+use crate::providers;
 
 /// Get application information
 #[command]
@@ -29,72 +29,93 @@ pub fn open_external_url(url: String) -> Result<(), String> {
 
 // ============================================
 // Secure Credential Storage Commands
-// Uses system credential manager (Windows Credential Manager,
-// macOS Keychain, Linux Secret Service)
+// Backed by the configured CredentialProvider
+// (OS keyring by default, or an external secret
+// manager such as 1Password)
 // ============================================
 
-/// Store a credential in the system credential manager
-/// 
+/// Store a credential through the configured credential provider
+///
 /// # Arguments
 /// * `key` - Unique identifier for the credential (e.g., "acs.accessKey", "agent.copilot-studio.abc123.clientSecret")
 /// * `value` - The secret value to store
+/// * `expiration` - Optional unix timestamp the credential expires at, for
+///   short-lived tokens such as agent/ACS access tokens. Omit for
+///   credentials that should be kept indefinitely.
 #[command]
-pub fn store_credential(key: String, value: String) -> Result<(), String> {
-    let entry = Entry::new(SERVICE_NAME, &key)
-        .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-    
-    entry
-        .set_password(&value)
-        .map_err(|e| format!("Failed to store credential '{}': {}", key, e))
+pub fn store_credential(key: String, value: String, expiration: Option<i64>) -> Result<(), String> {
+    let envelope = match expiration {
+        Some(expiration) => CredentialEnvelope::expires(value, expiration),
+        None => CredentialEnvelope::never(value),
+    };
+    store_envelope(&key, &envelope)
 }
 
-/// Retrieve a credential from the system credential manager
-/// 
+/// Store a credential that's only valid for the lifetime of the current app session
+///
 /// # Arguments
 /// * `key` - Unique identifier for the credential
-/// 
+/// * `value` - The secret value to store
+#[command]
+pub fn store_session_credential(key: String, value: String) -> Result<(), String> {
+    store_envelope(&key, &CredentialEnvelope::session(value))
+}
+
+/// Retrieve a credential through the configured credential provider
+///
+/// # Arguments
+/// * `key` - Unique identifier for the credential
+///
 /// # Returns
-/// * `Ok(Some(value))` - The credential value if found
-/// * `Ok(None)` - If the credential doesn't exist
-/// * `Err(msg)` - If there was an error accessing the credential manager
+/// * `Ok(Some(value))` - The credential value, if found and not expired
+/// * `Ok(None)` - If the credential doesn't exist, or has expired
+/// * `Err(msg)` - If there was an error accessing the provider
 #[command]
 pub fn get_credential(key: String) -> Result<Option<String>, String> {
-    let entry = Entry::new(SERVICE_NAME, &key)
-        .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-    
-    match entry.get_password() {
-        Ok(password) => Ok(Some(password)),
-        Err(keyring::Error::NoEntry) => Ok(None),
-        Err(e) => Err(format!("Failed to retrieve credential '{}': {}", key, e)),
-    }
+    Ok(live_envelope(&key)?.map(|envelope| envelope.value().to_string()))
 }
 
-/// Delete a credential from the system credential manager
-/// 
+/// A credential value alongside how much longer it's valid for
+#[derive(Serialize)]
+pub struct CredentialWithTtl {
+    value: String,
+    /// Seconds remaining before expiration; `None` if this credential
+    /// doesn't carry a fixed expiration timestamp
+    ttl_seconds: Option<i64>,
+}
+
+/// Retrieve a credential along with its remaining lifetime, so the
+/// frontend can proactively refresh a token before joining a meeting
+///
 /// # Arguments
 /// * `key` - Unique identifier for the credential
-/// 
+#[command]
+pub fn get_credential_with_ttl(key: String) -> Result<Option<CredentialWithTtl>, String> {
+    Ok(live_envelope(&key)?.map(|envelope| CredentialWithTtl {
+        ttl_seconds: envelope.ttl_seconds(),
+        value: envelope.value().to_string(),
+    }))
+}
+
+/// Delete a credential through the configured credential provider
+///
+/// # Arguments
+/// * `key` - Unique identifier for the credential
+///
 /// # Returns
 /// * `Ok(true)` - The credential was deleted
 /// * `Ok(false)` - The credential didn't exist
-/// * `Err(msg)` - If there was an error accessing the credential manager
+/// * `Err(msg)` - If there was an error accessing the provider
 #[command]
 pub fn delete_credential(key: String) -> Result<bool, String> {
-    let entry = Entry::new(SERVICE_NAME, &key)
-        .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-    
-    match entry.delete_credential() {
-        Ok(()) => Ok(true),
-        Err(keyring::Error::NoEntry) => Ok(false),
-        Err(e) => Err(format!("Failed to delete credential '{}': {}", key, e)),
-    }
+    providers::provider().delete(&key)
 }
 
 /// Store multiple credentials at once (batch operation)
-/// 
+///
 /// # Arguments
 /// * `credentials` - Array of (key, value) pairs to store
-/// 
+///
 /// # Returns
 /// * `Ok(count)` - Number of credentials successfully stored
 /// * `Err(msg)` - If there was an error
@@ -102,74 +123,78 @@ pub fn delete_credential(key: String) -> Result<bool, String> {
 pub fn store_credentials_batch(credentials: Vec<(String, String)>) -> Result<usize, String> {
     let mut count = 0;
     for (key, value) in credentials {
-        let entry = Entry::new(SERVICE_NAME, &key)
-            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-        
-        entry
-            .set_password(&value)
-            .map_err(|e| format!("Failed to store credential '{}': {}", key, e))?;
-        
+        store_envelope(&key, &CredentialEnvelope::never(value))?;
         count += 1;
     }
     Ok(count)
 }
 
 /// Retrieve multiple credentials at once (batch operation)
-/// 
+///
 /// # Arguments
 /// * `keys` - Array of keys to retrieve
-/// 
+///
 /// # Returns
-/// * `Ok(map)` - Object with key -> value (only includes found credentials)
+/// * `Ok(map)` - Object with key -> value (only includes found, non-expired credentials)
 #[command]
 pub fn get_credentials_batch(keys: Vec<String>) -> Result<serde_json::Value, String> {
     let mut result = serde_json::Map::new();
-    
-    for key in keys {
-        let entry = Entry::new(SERVICE_NAME, &key)
-            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-        
-        match entry.get_password() {
-            Ok(password) => {
-                result.insert(key, serde_json::Value::String(password));
-            }
-            Err(keyring::Error::NoEntry) => {
-                // Skip missing credentials
-            }
-            Err(e) => {
-                return Err(format!("Failed to retrieve credential '{}': {}", key, e));
-            }
+    for (key, raw) in providers::provider().get_batch(&keys)? {
+        let envelope = envelope_from_raw(raw);
+        if !envelope.is_expired() {
+            result.insert(key, serde_json::Value::String(envelope.value().to_string()));
         }
     }
-    
     Ok(serde_json::Value::Object(result))
 }
 
 /// Delete multiple credentials at once (batch operation)
-/// 
+///
 /// # Arguments
 /// * `keys` - Array of keys to delete
-/// 
+///
 /// # Returns
 /// * `Ok(count)` - Number of credentials actually deleted (excludes non-existent)
 #[command]
 pub fn delete_credentials_batch(keys: Vec<String>) -> Result<usize, String> {
+    let provider = providers::provider();
     let mut count = 0;
-    
     for key in keys {
-        let entry = Entry::new(SERVICE_NAME, &key)
-            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
-        
-        match entry.delete_credential() {
-            Ok(()) => count += 1,
-            Err(keyring::Error::NoEntry) => {
-                // Skip non-existent credentials
-            }
-            Err(e) => {
-                return Err(format!("Failed to delete credential '{}': {}", key, e));
-            }
+        if provider.delete(&key)? {
+            count += 1;
         }
     }
-    
     Ok(count)
 }
+
+/// Serialize an envelope and store it through the configured credential provider
+fn store_envelope(key: &str, envelope: &CredentialEnvelope) -> Result<(), String> {
+    let json = serde_json::to_string(envelope).map_err(|e| e.to_string())?;
+    providers::provider().set(key, &json)
+}
+
+/// Deserialize a stored value into a credential envelope
+///
+/// Values that aren't envelope JSON are treated as legacy plaintext (stored
+/// before envelopes existed, or coming from a provider like 1Password that
+/// hands back the raw secret) and wrapped as `CredentialEnvelope::Never`
+/// rather than failing.
+fn envelope_from_raw(raw: String) -> CredentialEnvelope {
+    serde_json::from_str(&raw).unwrap_or_else(|_| CredentialEnvelope::never(raw))
+}
+
+/// Fetch and deserialize a credential envelope, treating expired credentials as absent
+fn live_envelope(key: &str) -> Result<Option<CredentialEnvelope>, String> {
+    let raw = match providers::provider().get(key)? {
+        Some(raw) => raw,
+        None => return Ok(None),
+    };
+
+    let envelope = envelope_from_raw(raw);
+
+    if envelope.is_expired() {
+        Ok(None)
+    } else {
+        Ok(Some(envelope))
+    }
+}
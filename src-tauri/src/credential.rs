@@ -0,0 +1,128 @@
+// ============================================
+// Credential Envelope
+// Session-aware credential model: every stored
+// secret carries cache-control metadata so short-
+// lived tokens (agent/ACS access tokens) can expire
+// on their own schedule
+// ============================================
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+static SESSION_ID: OnceLock<String> = OnceLock::new();
+
+/// A random id minted once per process start, used to scope "session" credentials
+fn current_session_id() -> &'static str {
+    SESSION_ID.get_or_init(|| format!("{:016x}", rand::random::<u64>()))
+}
+
+/// A stored secret plus cache-control metadata
+///
+/// Serialized with an internally tagged `cache` field, so the JSON looks
+/// like `{ "value": ..., "cache": "expires", "expiration": <unix_ts> }`.
+/// A single flattened tag keeps the envelope forward-compatible: unknown
+/// extra fields added by a future version are simply ignored on decode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "cache", rename_all = "lowercase")]
+pub enum CredentialEnvelope {
+    /// Expires at a specific unix timestamp, e.g. an ACS/agent access token
+    Expires { value: String, expiration: i64 },
+    /// Valid only for the lifetime of the app session that stored it
+    Session { value: String, session_id: String },
+    /// Never expires (the historical default)
+    Never { value: String },
+}
+
+impl CredentialEnvelope {
+    /// Wrap a plain secret that never expires
+    pub fn never(value: String) -> Self {
+        CredentialEnvelope::Never { value }
+    }
+
+    /// Wrap a secret that's only valid for the currently running app session
+    pub fn session(value: String) -> Self {
+        CredentialEnvelope::Session {
+            value,
+            session_id: current_session_id().to_string(),
+        }
+    }
+
+    /// Wrap a secret that expires at a specific unix timestamp
+    pub fn expires(value: String, expiration: i64) -> Self {
+        CredentialEnvelope::Expires { value, expiration }
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            CredentialEnvelope::Expires { value, .. } => value,
+            CredentialEnvelope::Session { value, .. } => value,
+            CredentialEnvelope::Never { value } => value,
+        }
+    }
+
+    /// Whether this credential is past its expiration
+    pub fn is_expired(&self) -> bool {
+        match self {
+            CredentialEnvelope::Expires { expiration, .. } => now() >= *expiration,
+            CredentialEnvelope::Session { session_id, .. } => session_id != current_session_id(),
+            CredentialEnvelope::Never { .. } => false,
+        }
+    }
+
+    /// Seconds remaining before expiration, or `None` if this credential
+    /// doesn't carry a fixed expiration timestamp
+    pub fn ttl_seconds(&self) -> Option<i64> {
+        match self {
+            CredentialEnvelope::Expires { expiration, .. } => Some(expiration - now()),
+            _ => None,
+        }
+    }
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_expires_envelope_with_unknown_fields() {
+        let json = r#"{"cache":"expires","expiration":1,"value":"secret","futureField":"ignored"}"#;
+        let envelope: CredentialEnvelope = serde_json::from_str(json).unwrap();
+        assert_eq!(envelope.value(), "secret");
+        assert!(envelope.is_expired());
+    }
+
+    #[test]
+    fn deserializes_session_envelope_with_unknown_fields() {
+        let json = format!(
+            r#"{{"cache":"session","value":"secret","session_id":"{}","futureField":"ignored"}}"#,
+            current_session_id()
+        );
+        let envelope: CredentialEnvelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(envelope.value(), "secret");
+        assert!(!envelope.is_expired());
+        assert_eq!(envelope.ttl_seconds(), None);
+    }
+
+    #[test]
+    fn session_envelope_from_a_previous_process_is_expired() {
+        let json = r#"{"cache":"session","value":"secret","session_id":"stale-session"}"#;
+        let envelope: CredentialEnvelope = serde_json::from_str(json).unwrap();
+        assert!(envelope.is_expired());
+    }
+
+    #[test]
+    fn deserializes_never_envelope_with_unknown_fields() {
+        let json = r#"{"cache":"never","value":"secret","futureField":"ignored"}"#;
+        let envelope: CredentialEnvelope = serde_json::from_str(json).unwrap();
+        assert_eq!(envelope.value(), "secret");
+        assert!(!envelope.is_expired());
+    }
+}
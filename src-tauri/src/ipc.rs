@@ -0,0 +1,209 @@
+// ============================================
+// IPC Server
+// Local request/response channel that lets the
+// companion `teams-agent-bridge` CLI fetch stored
+// credentials from the already-running desktop app
+// ============================================
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::thread;
+
+use interprocess::local_socket::{LocalSocketListener, LocalSocketStream};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::approval::{self, Requester};
+use crate::commands;
+
+/// Name of the local socket used for CLI <-> app communication
+/// (a Unix domain socket on Linux/macOS, a named pipe on Windows)
+#[cfg(windows)]
+pub const SOCKET_NAME: &str = "\\\\.\\pipe\\teams-agent-bridge";
+#[cfg(not(windows))]
+pub const SOCKET_NAME: &str = "/tmp/teams-agent-bridge.sock";
+
+/// A request sent by the CLI to the running desktop app
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    /// Fetch one or more credentials by key
+    GetCredentials { keys: Vec<String> },
+}
+
+/// A response sent back to the CLI
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    /// Key -> value map (keys that weren't found are omitted)
+    Credentials(HashMap<String, String>),
+    /// Something went wrong servicing the request
+    Error(String),
+}
+
+/// Start the IPC server on a background thread
+///
+/// Scripts and meeting-join automation talk to this socket through the
+/// `teams-agent-bridge` CLI instead of duplicating keyring logic. Every
+/// `GetCredentials` request is gated behind a user approval prompt shown in
+/// `app`, except from executables the user already approved with "remember
+/// for this session" (see [`crate::approval`]).
+pub fn start_server(app: AppHandle) {
+    thread::spawn(move || {
+        if let Err(e) = run_server(app) {
+            eprintln!("IPC server exited: {}", e);
+        }
+    });
+}
+
+fn run_server(app: AppHandle) -> std::io::Result<()> {
+    #[cfg(not(windows))]
+    let _ = std::fs::remove_file(SOCKET_NAME);
+
+    let listener = LocalSocketListener::bind(SOCKET_NAME)?;
+    for connection in listener.incoming() {
+        match connection {
+            // Each connection gets its own thread so a pending approval
+            // prompt (which can block for up to the approval timeout)
+            // doesn't head-of-line-block every other CLI client.
+            Ok(stream) => {
+                let app = app.clone();
+                thread::spawn(move || handle_client(stream, app));
+            }
+            Err(e) => eprintln!("IPC connection failed: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Handle a single request/response exchange on a freshly accepted connection
+fn handle_client(stream: LocalSocketStream, app: AppHandle) {
+    let requester = requester_identity(&stream);
+
+    let writer_stream = match stream.try_clone() {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("IPC stream clone failed: {}", e);
+            return;
+        }
+    };
+    let mut reader = BufReader::new(stream);
+    let mut writer = writer_stream;
+
+    let mut line = String::new();
+    if reader.read_line(&mut line).unwrap_or(0) == 0 {
+        return;
+    }
+
+    let response = match serde_json::from_str::<Request>(&line) {
+        Ok(Request::GetCredentials { keys }) => match approval::request_approval(&app, requester, &keys) {
+            Ok(true) => match commands::get_credentials_batch(keys) {
+                Ok(serde_json::Value::Object(map)) => Response::Credentials(
+                    map.into_iter()
+                        .filter_map(|(k, v)| v.as_str().map(|s| (k, s.to_string())))
+                        .collect(),
+                ),
+                Ok(_) => Response::Credentials(HashMap::new()),
+                Err(e) => Response::Error(e),
+            },
+            Ok(false) => Response::Error("credential access was denied".to_string()),
+            Err(e) => Response::Error(e),
+        },
+        Err(e) => Response::Error(format!("invalid request: {}", e)),
+    };
+
+    if let Ok(payload) = serde_json::to_string(&response) {
+        let _ = writeln!(writer, "{}", payload);
+    }
+}
+
+/// Resolve the identity (PID and executable path) of the process on the other end of `stream`
+fn requester_identity(stream: &LocalSocketStream) -> Requester {
+    let pid = peer_pid(stream);
+    let executable_path = pid.and_then(executable_path_for_pid);
+    Requester { pid, executable_path }
+}
+
+#[cfg(unix)]
+fn peer_pid(stream: &LocalSocketStream) -> Option<u32> {
+    use std::os::unix::io::AsRawFd;
+
+    let fd = stream.as_raw_fd();
+    let mut cred: libc::ucred = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+
+    let ret = unsafe {
+        libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_PEERCRED,
+            &mut cred as *mut _ as *mut libc::c_void,
+            &mut len,
+        )
+    };
+
+    if ret == 0 {
+        Some(cred.pid as u32)
+    } else {
+        None
+    }
+}
+
+#[cfg(windows)]
+fn peer_pid(stream: &LocalSocketStream) -> Option<u32> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::System::Pipes::GetNamedPipeClientProcessId;
+
+    let handle = stream.as_raw_handle();
+    let mut pid: u32 = 0;
+    let ok = unsafe { GetNamedPipeClientProcessId(handle as _, &mut pid) };
+    if ok != 0 {
+        Some(pid)
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn executable_path_for_pid(pid: u32) -> Option<String> {
+    std::fs::read_link(format!("/proc/{}/exe", pid))
+        .ok()
+        .map(|p| p.to_string_lossy().into_owned())
+}
+
+#[cfg(target_os = "macos")]
+fn executable_path_for_pid(pid: u32) -> Option<String> {
+    // macOS has no /proc; shell out to `ps` to resolve the executable path
+    let output = std::process::Command::new("ps")
+        .args(["-o", "comm=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if path.is_empty() {
+        None
+    } else {
+        Some(path)
+    }
+}
+
+#[cfg(windows)]
+fn executable_path_for_pid(pid: u32) -> Option<String> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::ProcessStatus::K32GetProcessImageFileNameW;
+    use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return None;
+        }
+
+        let mut buf = [0u16; 260];
+        let len = K32GetProcessImageFileNameW(handle, buf.as_mut_ptr(), buf.len() as u32);
+        CloseHandle(handle);
+
+        if len == 0 {
+            None
+        } else {
+            Some(String::from_utf16_lossy(&buf[..len as usize]))
+        }
+    }
+}
@@ -4,20 +4,69 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod approval;
+mod cli;
 mod commands;
+mod credential;
+mod ipc;
+mod providers;
+mod vault;
+
+use clap::Parser;
+
+/// `teams-agent-bridge` launches the desktop app by default, or acts as a
+/// credential-injection CLI when called with `get`/`exec`
+#[derive(Parser)]
+#[command(name = "teams-agent-bridge")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<cli::Commands>,
+}
 
 fn main() {
+    let args = Cli::parse();
+
+    let result = match args.command {
+        Some(cli::Commands::Get { key }) => cli::get(key),
+        Some(cli::Commands::Exec { keys, command }) => cli::exec(keys, command),
+        None => {
+            run_app();
+            Ok(())
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Start the IPC server and launch the Tauri desktop application
+fn run_app() {
+    vault::start_auto_lock_timer();
+
     tauri::Builder::default()
+        .setup(|app| {
+            ipc::start_server(app.handle().clone());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::get_app_info,
             commands::open_external_url,
             // Secure credential storage commands
             commands::store_credential,
+            commands::store_session_credential,
             commands::get_credential,
+            commands::get_credential_with_ttl,
             commands::delete_credential,
             commands::store_credentials_batch,
             commands::get_credentials_batch,
             commands::delete_credentials_batch,
+            // Encrypted vault commands
+            vault::unlock,
+            vault::lock,
+            // External credential access approval
+            approval::respond_to_approval,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
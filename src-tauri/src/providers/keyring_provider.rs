@@ -0,0 +1,68 @@
+use keyring::Entry;
+
+use super::{CredentialProvider, SERVICE_NAME};
+use crate::vault;
+
+/// Stores credentials in the OS credential manager (Windows Credential
+/// Manager, macOS Keychain, Linux Secret Service)
+///
+/// When a vault has been set up (see [`crate::vault`]), newly stored values
+/// are encrypted with the vault key before they ever reach the OS
+/// credential store, so a compromised or unlocked keyring doesn't leak
+/// plaintext secrets. Whether a *read* value needs decrypting is determined
+/// by tagging on the stored blob itself, not by whether a vault happens to
+/// be configured right now — so credentials written before the vault
+/// existed keep round-tripping as plaintext instead of failing to decrypt.
+#[derive(Default)]
+pub struct KeyringProvider;
+
+impl KeyringProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CredentialProvider for KeyringProvider {
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let entry = Entry::new(SERVICE_NAME, key)
+            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
+
+        let stored = match entry.get_password() {
+            Ok(password) => password,
+            Err(keyring::Error::NoEntry) => return Ok(None),
+            Err(e) => return Err(format!("Failed to retrieve credential '{}': {}", key, e)),
+        };
+
+        if vault::is_encrypted(&stored) {
+            vault::decrypt(&stored).map(Some)
+        } else {
+            Ok(Some(stored))
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        let entry = Entry::new(SERVICE_NAME, key)
+            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
+
+        let to_store = if vault::is_configured() {
+            vault::encrypt(value)?
+        } else {
+            value.to_string()
+        };
+
+        entry
+            .set_password(&to_store)
+            .map_err(|e| format!("Failed to store credential '{}': {}", key, e))
+    }
+
+    fn delete(&self, key: &str) -> Result<bool, String> {
+        let entry = Entry::new(SERVICE_NAME, key)
+            .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
+
+        match entry.delete_credential() {
+            Ok(()) => Ok(true),
+            Err(keyring::Error::NoEntry) => Ok(false),
+            Err(e) => Err(format!("Failed to delete credential '{}': {}", key, e)),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// ============================================
+// Credential Providers
+// Pluggable backends for credential storage, so
+// teams that already centralize secrets elsewhere
+// aren't forced to copy them into the per-user
+// system keychain
+// ============================================
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+mod keyring_provider;
+mod onepassword;
+
+pub use keyring_provider::KeyringProvider;
+pub use onepassword::OnePasswordProvider;
+
+/// Service name used by the `keyring` provider for all credentials
+pub const SERVICE_NAME: &str = "teams-agent-bridge";
+
+/// A source of truth for credential storage
+pub trait CredentialProvider: Send + Sync {
+    /// Retrieve a credential, or `Ok(None)` if it doesn't exist
+    fn get(&self, key: &str) -> Result<Option<String>, String>;
+
+    /// Store a credential
+    fn set(&self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Delete a credential, returning whether it existed
+    fn delete(&self, key: &str) -> Result<bool, String>;
+
+    /// Fetch several credentials at once
+    ///
+    /// The default implementation calls `get` for each key; providers that
+    /// can batch network calls (e.g. the 1Password CLI) may override this.
+    fn get_batch(&self, keys: &[String]) -> Result<HashMap<String, String>, String> {
+        let mut result = HashMap::new();
+        for key in keys {
+            if let Some(value) = self.get(key)? {
+                result.insert(key.clone(), value);
+            }
+        }
+        Ok(result)
+    }
+}
+
+static PROVIDER: OnceLock<Arc<dyn CredentialProvider>> = OnceLock::new();
+
+/// Resolve the configured credential provider
+///
+/// Selected once at startup from the `TEAMS_AGENT_BRIDGE_PROVIDER`
+/// environment variable ("keyring", the default, or "1password"), then
+/// reused for every command.
+pub fn provider() -> Arc<dyn CredentialProvider> {
+    PROVIDER
+        .get_or_init(|| match std::env::var("TEAMS_AGENT_BRIDGE_PROVIDER").as_deref() {
+            Ok("1password") => Arc::new(OnePasswordProvider::new()),
+            _ => Arc::new(KeyringProvider::new()),
+        })
+        .clone()
+}
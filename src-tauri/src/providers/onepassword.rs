@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::process::Command;
+
+use super::CredentialProvider;
+
+/// Shells out to the `op` 1Password CLI
+///
+/// Each credential key maps to a 1Password item of the same title: `get`
+/// runs `op list items` to resolve the key to an item UUID, then
+/// `op get item` and reads the `password` field out of `details.fields`.
+/// `get_batch` lists items once for the whole batch instead of once per key.
+#[derive(Default)]
+pub struct OnePasswordProvider;
+
+impl OnePasswordProvider {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// List all 1Password items as `(title, uuid)` pairs
+    fn list_items(&self) -> Result<Vec<(String, String)>, String> {
+        let output = Command::new("op")
+            .args(["list", "items"])
+            .output()
+            .map_err(|e| format!("failed to run 'op list items': {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "'op list items' failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let items: serde_json::Value =
+            serde_json::from_slice(&output.stdout).map_err(|e| format!("failed to parse 'op list items' output: {}", e))?;
+
+        Ok(items
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|item| {
+                let title = item.pointer("/overview/title")?.as_str()?.to_string();
+                let uuid = item.get("uuid")?.as_str()?.to_string();
+                Some((title, uuid))
+            })
+            .collect())
+    }
+
+    /// Resolve a credential key to the UUID of the 1Password item with a matching title
+    fn find_item_uuid(&self, key: &str) -> Result<Option<String>, String> {
+        Ok(self
+            .list_items()?
+            .into_iter()
+            .find(|(title, _)| title == key)
+            .map(|(_, uuid)| uuid))
+    }
+
+    /// Fetch the `password` field of an item by UUID
+    fn get_item_password(&self, uuid: &str) -> Result<Option<String>, String> {
+        let output = Command::new("op")
+            .args(["get", "item", uuid])
+            .output()
+            .map_err(|e| format!("failed to run 'op get item': {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "'op get item {}' failed: {}",
+                uuid,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let item: serde_json::Value =
+            serde_json::from_slice(&output.stdout).map_err(|e| format!("failed to parse 'op get item' output: {}", e))?;
+
+        Ok(item
+            .pointer("/details/fields")
+            .and_then(|f| f.as_array())
+            .into_iter()
+            .flatten()
+            .find(|field| field.get("designation").and_then(|d| d.as_str()) == Some("password"))
+            .and_then(|field| field.get("value").and_then(|v| v.as_str()))
+            .map(|s| s.to_string()))
+    }
+}
+
+impl CredentialProvider for OnePasswordProvider {
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        match self.find_item_uuid(key)? {
+            Some(uuid) => self.get_item_password(&uuid),
+            None => Ok(None),
+        }
+    }
+
+    fn set(&self, _key: &str, _value: &str) -> Result<(), String> {
+        Err("storing credentials is not supported for the 1Password provider; manage items in 1Password directly".to_string())
+    }
+
+    fn delete(&self, _key: &str) -> Result<bool, String> {
+        Err("deleting credentials is not supported for the 1Password provider; manage items in 1Password directly".to_string())
+    }
+
+    fn get_batch(&self, keys: &[String]) -> Result<HashMap<String, String>, String> {
+        let items = self.list_items()?;
+
+        let mut result = HashMap::new();
+        for key in keys {
+            let Some((_, uuid)) = items.iter().find(|(title, _)| title == key) else {
+                continue;
+            };
+            if let Some(value) = self.get_item_password(uuid)? {
+                result.insert(key.clone(), value);
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,282 @@
+// ============================================
+// Encrypted Vault
+// Wraps credential values with a ChaCha20-Poly1305
+// cipher keyed by a user passphrase (Argon2id), so
+// stored secrets stay protected even if the OS
+// credential store is compromised or left unlocked
+// ============================================
+
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, Params, Version};
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use tauri::command;
+use zeroize::Zeroizing;
+
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Prefix marking a stored value as vault-encrypted
+///
+/// Whether a given value needs decrypting is read off the value itself
+/// rather than inferred from whether a vault is currently configured, so
+/// plaintext values written before the vault was set up keep working as
+/// plain passthrough after `unlock` is called.
+const ENCRYPTED_PREFIX: &str = "enc1:";
+
+/// Default idle timeout before the vault auto-locks, in seconds
+const DEFAULT_AUTO_LOCK_SECS: u64 = 15 * 60;
+
+/// Known plaintext sealed under the derived key at setup time, so `unlock`
+/// can tell a wrong passphrase apart from the right one before handing out
+/// a key that would silently corrupt anything encrypted under it
+const VERIFIER_PLAINTEXT: &str = "teams-agent-bridge-vault-verifier";
+
+/// Argon2id parameters and salt persisted alongside the vault (never the passphrase)
+#[derive(Serialize, Deserialize)]
+struct VaultMetadata {
+    salt: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    /// `VERIFIER_PLAINTEXT` sealed under the key derived at setup time
+    verifier: String,
+}
+
+struct VaultState {
+    key: Mutex<Option<Zeroizing<[u8; KEY_LEN]>>>,
+    last_activity: Mutex<Instant>,
+}
+
+static VAULT: OnceLock<VaultState> = OnceLock::new();
+
+fn state() -> &'static VaultState {
+    VAULT.get_or_init(|| VaultState {
+        key: Mutex::new(None),
+        last_activity: Mutex::new(Instant::now()),
+    })
+}
+
+fn metadata_path() -> Result<std::path::PathBuf, String> {
+    let dir = dirs::config_dir()
+        .ok_or("could not determine the config directory")?
+        .join("teams-agent-bridge");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("vault.json"))
+}
+
+/// Whether a vault has already been set up on this machine
+pub fn is_configured() -> bool {
+    metadata_path().map(|p| p.exists()).unwrap_or(false)
+}
+
+/// Whether `stored` is a vault-encrypted blob (as opposed to legacy plaintext)
+pub fn is_encrypted(stored: &str) -> bool {
+    stored.starts_with(ENCRYPTED_PREFIX)
+}
+
+fn load_metadata() -> Result<VaultMetadata, String> {
+    let path = metadata_path()?;
+    let bytes = std::fs::read(&path).map_err(|e| format!("failed to read vault metadata: {}", e))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("failed to parse vault metadata: {}", e))
+}
+
+fn save_metadata(metadata: &VaultMetadata) -> Result<(), String> {
+    let path = metadata_path()?;
+    let bytes = serde_json::to_vec_pretty(metadata).map_err(|e| e.to_string())?;
+    std::fs::write(&path, bytes).map_err(|e| format!("failed to write vault metadata: {}", e))
+}
+
+fn derive_key(passphrase: &str, metadata: &VaultMetadata) -> Result<Zeroizing<[u8; KEY_LEN]>, String> {
+    let salt = SaltString::from_b64(&metadata.salt).map_err(|e| format!("invalid vault salt: {}", e))?;
+    let params = Params::new(metadata.m_cost, metadata.t_cost, metadata.p_cost, Some(KEY_LEN))
+        .map_err(|e| format!("invalid Argon2 parameters: {}", e))?;
+    let argon2 = Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    argon2
+        .hash_password_into(passphrase.as_bytes(), salt.as_str().as_bytes(), key.as_mut_slice())
+        .map_err(|e| format!("failed to derive vault key: {}", e))?;
+    Ok(key)
+}
+
+/// Derive (and, on first run, set up) the vault key from a passphrase, then hold it in memory
+///
+/// On first call this generates a random salt and Argon2id parameters,
+/// seals a verifier under the derived key, and persists all of it (never
+/// the passphrase itself). Subsequent calls derive the key from the saved
+/// salt/parameters and reject the passphrase if it can't open that
+/// verifier, so a typo can't silently re-encrypt credentials under the
+/// wrong key.
+#[command]
+pub fn unlock(passphrase: String) -> Result<(), String> {
+    let key = if is_configured() {
+        let metadata = load_metadata()?;
+        let key = derive_key(&passphrase, &metadata)?;
+        if open_with_key(&key, &metadata.verifier) != Ok(VERIFIER_PLAINTEXT.to_string()) {
+            return Err("incorrect passphrase".to_string());
+        }
+        key
+    } else {
+        let mut metadata = VaultMetadata {
+            salt: SaltString::generate(&mut OsRng).as_str().to_string(),
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+            verifier: String::new(),
+        };
+        let key = derive_key(&passphrase, &metadata)?;
+        metadata.verifier = seal_with_key(&key, VERIFIER_PLAINTEXT)?;
+        save_metadata(&metadata)?;
+        key
+    };
+
+    *state().key.lock().unwrap() = Some(key);
+    touch();
+    Ok(())
+}
+
+/// Zeroize the in-memory vault key, requiring `unlock` again before credentials can be read
+#[command]
+pub fn lock() {
+    *state().key.lock().unwrap() = None;
+}
+
+/// Whether the vault is currently unlocked
+pub fn is_unlocked() -> bool {
+    state().key.lock().unwrap().is_some()
+}
+
+/// Record activity so the auto-lock timer doesn't fire while the vault is in use
+fn touch() {
+    *state().last_activity.lock().unwrap() = Instant::now();
+}
+
+/// Start the background auto-lock timer on a dedicated thread
+///
+/// Zeroizes the in-memory key after `TEAMS_AGENT_BRIDGE_AUTO_LOCK_SECS`
+/// seconds (default 15 minutes) of inactivity.
+pub fn start_auto_lock_timer() {
+    let timeout = std::env::var("TEAMS_AGENT_BRIDGE_AUTO_LOCK_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(DEFAULT_AUTO_LOCK_SECS));
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(5));
+        let idle = state().last_activity.lock().unwrap().elapsed();
+        if idle >= timeout {
+            lock();
+        }
+    });
+}
+
+/// Encrypt a credential value, prepending the random 12-byte nonce to the
+/// ciphertext and tagging the result with `ENCRYPTED_PREFIX`
+pub fn encrypt(plaintext: &str) -> Result<String, String> {
+    let key_guard = state().key.lock().unwrap();
+    let key = key_guard.as_ref().ok_or("vault is locked; call unlock first")?;
+    let sealed = seal_with_key(key, plaintext)?;
+    drop(key_guard);
+    touch();
+
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, sealed))
+}
+
+/// Decrypt a value produced by `encrypt`
+pub fn decrypt(stored: &str) -> Result<String, String> {
+    let stored = stored
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or("stored credential is not vault-encrypted")?;
+
+    let key_guard = state().key.lock().unwrap();
+    let key = key_guard.as_ref().ok_or("vault is locked; call unlock first")?;
+    let plaintext = open_with_key(key, stored).map_err(|e| format!("failed to decrypt credential: {}", e))?;
+    drop(key_guard);
+    touch();
+
+    Ok(plaintext)
+}
+
+/// Seal `plaintext` under `key`, prepending the random 12-byte nonce to the ciphertext
+fn seal_with_key(key: &[u8; KEY_LEN], plaintext: &str) -> Result<String, String> {
+    let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_bytes())
+        .map_err(|e| format!("failed to seal value: {}", e))?;
+
+    let mut combined = nonce_bytes.to_vec();
+    combined.extend(ciphertext);
+    Ok(base64::engine::general_purpose::STANDARD.encode(combined))
+}
+
+/// Open a value produced by `seal_with_key`
+fn open_with_key(key: &[u8; KEY_LEN], stored: &str) -> Result<String, String> {
+    let cipher = ChaCha20Poly1305::new(key.as_slice().into());
+
+    let combined = base64::engine::general_purpose::STANDARD
+        .decode(stored)
+        .map_err(|e| format!("failed to decode stored value: {}", e))?;
+    if combined.len() < NONCE_LEN {
+        return Err("stored value is corrupt".to_string());
+    }
+    let (nonce_bytes, ciphertext) = combined.split_at(NONCE_LEN);
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    let plaintext = cipher.decrypt(nonce, ciphertext).map_err(|e| e.to_string())?;
+    String::from_utf8(plaintext).map_err(|e| format!("decrypted value is not valid UTF-8: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_test_key(byte: u8) {
+        *state().key.lock().unwrap() = Some(Zeroizing::new([byte; KEY_LEN]));
+    }
+
+    #[test]
+    fn encrypt_decrypt_and_lock_semantics() {
+        set_test_key(7);
+
+        let ciphertext = encrypt("top secret").unwrap();
+        assert_ne!(ciphertext, "top secret");
+        assert!(is_encrypted(&ciphertext));
+        assert_eq!(decrypt(&ciphertext).unwrap(), "top secret");
+
+        lock();
+        assert!(encrypt("another secret").unwrap_err().contains("locked"));
+        assert!(decrypt(&ciphertext).unwrap_err().contains("locked"));
+
+        // Unlocking with a different key can't decrypt ciphertext sealed under the old one
+        set_test_key(9);
+        assert!(decrypt(&ciphertext).is_err());
+    }
+
+    #[test]
+    fn unencrypted_values_are_not_flagged_as_encrypted() {
+        assert!(!is_encrypted("a plain legacy secret"));
+    }
+
+    #[test]
+    fn verifier_rejects_the_wrong_key() {
+        let verifier = seal_with_key(&[1u8; KEY_LEN], VERIFIER_PLAINTEXT).unwrap();
+        assert_eq!(
+            open_with_key(&[1u8; KEY_LEN], &verifier).as_deref(),
+            Ok(VERIFIER_PLAINTEXT)
+        );
+        assert!(open_with_key(&[2u8; KEY_LEN], &verifier).is_err());
+    }
+}